@@ -20,8 +20,93 @@
 #![forbid(unsafe_code)]
 #![deny(clippy::unwrap_used)]
 
+use std::str::FromStr;
+
 use proc_macro2::{Ident, Span};
 
+/// The casing a type name/identifier should be rendered in.
+///
+/// Modeled after the set of styles derive-macro crates typically support, so a
+/// macro author can accept a `#[case = "camelCase"]`-style attribute and feed
+/// the parsed value straight into [`ToTypeName::to_case`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseStyle {
+    /// `FooBarBaz`
+    PascalCase,
+    /// `fooBarBaz`
+    CamelCase,
+    /// `foo_bar_baz`
+    SnakeCase,
+    /// `foo-bar-baz`
+    KebabCase,
+    /// `FOO_BAR_BAZ`
+    ScreamingSnakeCase,
+    /// `FOO-BAR-BAZ`
+    ScreamingKebabCase,
+    /// `Foo-Bar-Baz`
+    TrainCase,
+    /// `Foo Bar Baz`
+    TitleCase,
+    /// `foobarbaz`
+    LowerCase,
+    /// `FOOBARBAZ`
+    UpperCase,
+}
+
+impl FromStr for CaseStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "PascalCase" => CaseStyle::PascalCase,
+            "camelCase" => CaseStyle::CamelCase,
+            "snake_case" => CaseStyle::SnakeCase,
+            "kebab-case" => CaseStyle::KebabCase,
+            "SCREAMING_SNAKE_CASE" => CaseStyle::ScreamingSnakeCase,
+            "SCREAMING-KEBAB-CASE" => CaseStyle::ScreamingKebabCase,
+            "Train-Case" => CaseStyle::TrainCase,
+            "Title Case" => CaseStyle::TitleCase,
+            "lowercase" => CaseStyle::LowerCase,
+            "UPPERCASE" => CaseStyle::UpperCase,
+            other => return Err(format!("unknown case style: {other:?}")),
+        })
+    }
+}
+
+impl syn::parse::Parse for CaseStyle {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lit = input.parse::<syn::LitStr>()?;
+        lit.value()
+            .parse()
+            .map_err(|err: String| syn::Error::new(lit.span(), err))
+    }
+}
+
+/// Reasons a name cannot be converted into a valid Rust type identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaseError {
+    /// The input contained whitespace, which cannot appear in an identifier.
+    InvalidWhitespace,
+    /// The input produced no words, or a word that was empty.
+    EmptySegment,
+    /// The cased result is not a valid Rust identifier.
+    InvalidIdentifier(String),
+}
+
+impl std::fmt::Display for CaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaseError::InvalidWhitespace => f.write_str("input contained whitespace"),
+            CaseError::EmptySegment => f.write_str("input produced an empty segment"),
+            CaseError::InvalidIdentifier(name) => {
+                write!(f, "{name:?} is not a valid Rust identifier")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CaseError {}
+
 /// Helper trait for generating a (pascal case) Rust type name/identifier.
 /// Particularly helpful in derive macros when generating enum variants for struct fields.
 pub trait ToTypeName {
@@ -30,24 +115,231 @@ pub trait ToTypeName {
     fn to_type_ident(&self, span: Span) -> Ident {
         Ident::new(self.to_type_name().as_str(), span)
     }
+
+    /// Render this name in the requested [`CaseStyle`].
+    fn to_case(&self, style: CaseStyle) -> String {
+        to_case(self.to_type_name_source().as_str(), style)
+    }
+
+    /// Like [`to_case`](ToTypeName::to_case), but produces an [`Ident`] at `span`.
+    fn to_case_ident(&self, style: CaseStyle, span: Span) -> Ident {
+        Ident::new(self.to_case(style).as_str(), span)
+    }
+
+    /// Render this name in `style` as an [`Ident`], guaranteed not to panic:
+    /// reserved keywords are turned into raw identifiers (`r#name`) where legal or
+    /// suffixed with `_` where raw form is disallowed (`self`, `super`, `crate`, `Self`),
+    /// a leading numeric character is prefixed with `_`, and an empty result (e.g. an
+    /// all-separator input) falls back to `_`.
+    ///
+    /// Use this whenever the source name comes from outside the macro (JSON keys,
+    /// DB columns, FFI symbols) and cannot be trusted to be a valid identifier.
+    /// Lower-casing styles such as [`CaseStyle::SnakeCase`] let the result land on a
+    /// bare keyword, which is where the raw-identifier escaping applies.
+    fn to_safe_case_ident(&self, style: CaseStyle, span: Span) -> Ident {
+        make_safe_ident(self.to_case(style).as_str(), span)
+    }
+
+    /// The string form of [`to_safe_case_ident`](ToTypeName::to_safe_case_ident),
+    /// e.g. `r#match` or `self_`.
+    fn to_safe_case_name(&self, style: CaseStyle) -> String {
+        self.to_safe_case_ident(style, Span::call_site()).to_string()
+    }
+
+    /// [`to_safe_case_ident`](ToTypeName::to_safe_case_ident) specialized to the
+    /// crate's default [`CaseStyle::PascalCase`] type-name casing.
+    fn to_safe_type_ident(&self, span: Span) -> Ident {
+        self.to_safe_case_ident(CaseStyle::PascalCase, span)
+    }
+
+    /// The string form of [`to_safe_type_ident`](ToTypeName::to_safe_type_ident).
+    fn to_safe_type_name(&self) -> String {
+        self.to_safe_type_ident(Span::call_site()).to_string()
+    }
+
+    /// Like [`to_type_name`](ToTypeName::to_type_name), but returns a [`CaseError`]
+    /// instead of panicking when the input cannot yield a valid identifier.
+    fn try_to_type_name(&self) -> Result<String, CaseError> {
+        let name = try_to_pascal_case(self.to_type_name_source().as_str())?;
+        match syn::parse_str::<Ident>(name.as_str()) {
+            Ok(_) => Ok(name),
+            Err(_) => Err(CaseError::InvalidIdentifier(name)),
+        }
+    }
+
+    /// Like [`to_type_ident`](ToTypeName::to_type_ident), but returns a [`CaseError`]
+    /// instead of panicking. Macro authors can map the error onto a `syn::Error` at
+    /// the offending span rather than aborting the compiler.
+    fn try_to_type_ident(&self, span: Span) -> Result<Ident, CaseError> {
+        let name = self.try_to_type_name()?;
+        syn::parse_str::<Ident>(name.as_str())
+            .map(|_| Ident::new(name.as_str(), span))
+            .map_err(|_| CaseError::InvalidIdentifier(name))
+    }
+
+    /// The raw source string this conversion operates on, before any casing.
+    fn to_type_name_source(&self) -> String;
+}
+
+fn try_to_pascal_case(input: &str) -> Result<String, CaseError> {
+    if input.chars().any(char::is_whitespace) {
+        return Err(CaseError::InvalidWhitespace);
+    }
+    let words = words(input);
+    if words.is_empty() || words.iter().any(|word| word.is_empty()) {
+        return Err(CaseError::EmptySegment);
+    }
+    Ok(words
+        .iter()
+        .map(|word| capitalize_first_letter(word))
+        .collect())
+}
+
+/// Rust keywords (strict and reserved) that must not appear bare as identifiers.
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+/// Keywords that cannot be expressed as raw identifiers and must be suffixed instead.
+const NON_RAW_KEYWORDS: &[&str] = &["self", "Self", "super", "crate"];
+
+fn make_safe_ident(name: &str, span: Span) -> Ident {
+    // Replace every character that cannot legally appear in an identifier (`.`, `$`,
+    // `@`, separators emitted by kebab/title styles, ...) with `_` so `Ident::new`
+    // never sees illegal input.
+    let mut name: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    // An identifier may neither be empty nor start with a digit.
+    if name.is_empty() || name.chars().next().is_some_and(|c| c.is_numeric()) {
+        name.insert(0, '_');
+    }
+    if NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        Ident::new(format!("{name}_").as_str(), span)
+    } else if KEYWORDS.contains(&name.as_str()) {
+        Ident::new_raw(name.as_str(), span)
+    } else {
+        Ident::new(name.as_str(), span)
+    }
 }
 
 impl ToTypeName for &str {
     fn to_type_name(&self) -> String {
         to_pascal_case(self)
     }
+
+    fn to_type_name_source(&self) -> String {
+        (*self).to_owned()
+    }
 }
 
 impl ToTypeName for String {
     fn to_type_name(&self) -> String {
         to_pascal_case(self.as_str())
     }
+
+    fn to_type_name_source(&self) -> String {
+        self.clone()
+    }
 }
 
 impl ToTypeName for &Ident {
     fn to_type_name(&self) -> String {
         to_pascal_case(self.to_string().as_str())
     }
+
+    fn to_type_name_source(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Split `input` into its constituent, lower-cased words.
+///
+/// Word boundaries are detected at separators (`_`, `-`, ` `), at
+/// lowercase/digit→uppercase transitions (`fooBar` → `foo` + `Bar`), at the end
+/// of an acronym that runs into a new word (`HTTPResponse` → `HTTP` + `Response`),
+/// and at letter↔digit transitions (`v2Api` → `v` + `2` + `Api`). Each resulting
+/// word is lower-cased so callers only have to re-apply the desired casing.
+fn words(input: &str) -> Vec<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if !current.is_empty() {
+            let prev = chars[i - 1];
+            let lower_to_upper =
+                (prev.is_lowercase() || prev.is_ascii_digit()) && c.is_uppercase();
+            let acronym_end = prev.is_uppercase()
+                && c.is_uppercase()
+                && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            let letter_digit = prev.is_alphabetic() && c.is_ascii_digit();
+            let digit_letter = prev.is_ascii_digit() && c.is_alphabetic();
+            if lower_to_upper || acronym_end || letter_digit || digit_letter {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words.iter().map(|word| word.to_lowercase()).collect()
+}
+
+fn to_case(input: &str, style: CaseStyle) -> String {
+    let words = words(input);
+    match style {
+        CaseStyle::PascalCase => words
+            .iter()
+            .map(|word| capitalize_first_letter(word))
+            .collect(),
+        CaseStyle::CamelCase => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.clone()
+                } else {
+                    capitalize_first_letter(word)
+                }
+            })
+            .collect(),
+        CaseStyle::SnakeCase => words.join("_"),
+        CaseStyle::KebabCase => words.join("-"),
+        CaseStyle::ScreamingSnakeCase => join_uppercased(&words, "_"),
+        CaseStyle::ScreamingKebabCase => join_uppercased(&words, "-"),
+        CaseStyle::TrainCase => words
+            .iter()
+            .map(|word| capitalize_first_letter(word))
+            .collect::<Vec<_>>()
+            .join("-"),
+        CaseStyle::TitleCase => words
+            .iter()
+            .map(|word| capitalize_first_letter(word))
+            .collect::<Vec<_>>()
+            .join(" "),
+        CaseStyle::LowerCase => words.join(""),
+        CaseStyle::UpperCase => join_uppercased(&words, ""),
+    }
+}
+
+fn join_uppercased(words: &[String], separator: &str) -> String {
+    words
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>()
+        .join(separator)
 }
 
 fn to_pascal_case(snake_case: &str) -> String {
@@ -55,15 +347,15 @@ fn to_pascal_case(snake_case: &str) -> String {
         .as_bytes()
         .iter()
         .any(|c| c.is_ascii_whitespace()));
-    let mut pascal_case = String::new();
-    for part in snake_case.split(&['_', '-']) {
-        pascal_case.push_str(capitalize_first_letter(part).as_str());
-    }
-    pascal_case
+    to_case(snake_case, CaseStyle::PascalCase)
 }
 
 fn capitalize_first_letter(s: &str) -> String {
-    s[0..1].to_uppercase() + &s[1..]
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
 }
 
 #[cfg(test)]
@@ -73,6 +365,8 @@ mod test {
 
     use crate::capitalize_first_letter;
     use crate::to_pascal_case;
+    use crate::CaseError;
+    use crate::CaseStyle;
     use crate::ToTypeName;
 
     #[test]
@@ -107,6 +401,124 @@ mod test {
         assert_eq!(to_pascal_case("foo_bar-bazBrr"), "FooBarBazBrr".to_owned());
     }
 
+    #[test]
+    fn case_style_parses_from_str() {
+        assert_eq!("PascalCase".parse(), Ok(CaseStyle::PascalCase));
+        assert_eq!("camelCase".parse(), Ok(CaseStyle::CamelCase));
+        assert_eq!("snake_case".parse(), Ok(CaseStyle::SnakeCase));
+        assert_eq!("kebab-case".parse(), Ok(CaseStyle::KebabCase));
+        assert!("nonsense".parse::<CaseStyle>().is_err());
+    }
+
+    #[test]
+    fn to_case_renders_each_style() {
+        let input = "foo_bar-baz";
+        assert_eq!((&input).to_case(CaseStyle::PascalCase), "FooBarBaz");
+        assert_eq!((&input).to_case(CaseStyle::CamelCase), "fooBarBaz");
+        assert_eq!((&input).to_case(CaseStyle::SnakeCase), "foo_bar_baz");
+        assert_eq!((&input).to_case(CaseStyle::KebabCase), "foo-bar-baz");
+        assert_eq!(
+            (&input).to_case(CaseStyle::ScreamingSnakeCase),
+            "FOO_BAR_BAZ"
+        );
+        assert_eq!(
+            (&input).to_case(CaseStyle::ScreamingKebabCase),
+            "FOO-BAR-BAZ"
+        );
+        assert_eq!((&input).to_case(CaseStyle::TrainCase), "Foo-Bar-Baz");
+        assert_eq!((&input).to_case(CaseStyle::TitleCase), "Foo Bar Baz");
+        assert_eq!((&input).to_case(CaseStyle::LowerCase), "foobarbaz");
+        assert_eq!((&input).to_case(CaseStyle::UpperCase), "FOOBARBAZ");
+    }
+
+    #[test]
+    fn to_pascal_case_segments_mixed_case_and_digits() {
+        assert_eq!(to_pascal_case("fooBarBazBrr"), "FooBarBazBrr".to_owned());
+        assert_eq!(
+            to_pascal_case("parseHTTPResponse"),
+            "ParseHttpResponse".to_owned()
+        );
+        assert_eq!(to_pascal_case("HTTPResponse"), "HttpResponse".to_owned());
+        assert_eq!(to_pascal_case("v2_api"), "V2Api".to_owned());
+        assert_eq!(to_pascal_case("v2Api"), "V2Api".to_owned());
+    }
+
+    #[test]
+    fn to_safe_type_ident_sanitizes_keywords() {
+        // `Self` is a keyword that cannot be raw -> suffixed.
+        assert_eq!((&"self").to_safe_type_name(), "Self_");
+        // `Match` pascal-cases away from the keyword and stays untouched.
+        assert_eq!((&"match").to_safe_type_name(), "Match");
+    }
+
+    #[test]
+    fn to_safe_case_ident_raw_escapes_lowercase_keywords() {
+        // A casing that lands straight on a keyword becomes a raw identifier.
+        assert_eq!((&"type").to_safe_case_name(CaseStyle::SnakeCase), "r#type");
+        assert_eq!((&"match").to_safe_case_name(CaseStyle::SnakeCase), "r#match");
+    }
+
+    #[test]
+    fn to_safe_type_name_never_panics_on_hostile_input() {
+        assert_eq!((&"foo bar").to_safe_type_name(), "FooBar");
+        assert_eq!((&"").to_safe_type_name(), "_");
+        assert_eq!((&"___").to_safe_type_name(), "_");
+    }
+
+    #[test]
+    fn to_safe_type_name_sanitizes_punctuation() {
+        assert_eq!((&"foo.bar").to_safe_type_name(), "Foo_bar");
+        assert_eq!((&"foo$bar").to_safe_type_name(), "Foo_bar");
+        assert_eq!((&"a@b").to_safe_type_name(), "A_b");
+        assert_eq!((&"col#1").to_safe_type_name(), "Col_1");
+        assert_eq!((&"flag?").to_safe_type_name(), "Flag_");
+    }
+
+    #[test]
+    fn to_safe_case_ident_handles_separator_styles() {
+        // Styles that emit separators must not reach `Ident::new` with them.
+        assert_eq!(
+            (&"foo_bar").to_safe_case_name(CaseStyle::KebabCase),
+            "foo_bar"
+        );
+        assert_eq!(
+            (&"foo_bar").to_safe_case_name(CaseStyle::TrainCase),
+            "Foo_Bar"
+        );
+        assert_eq!(
+            (&"foo_bar").to_safe_case_name(CaseStyle::TitleCase),
+            "Foo_Bar"
+        );
+        assert_eq!(
+            (&"foo_bar").to_safe_case_name(CaseStyle::ScreamingKebabCase),
+            "FOO_BAR"
+        );
+    }
+
+    #[test]
+    fn to_safe_type_ident_prefixes_leading_digit() {
+        assert_eq!((&"2fa").to_safe_type_name(), "_2Fa");
+    }
+
+    #[test]
+    fn capitalize_first_letter_handles_multibyte_chars() {
+        assert_eq!(capitalize_first_letter("ärger"), "Ärger".to_owned());
+        assert_eq!(capitalize_first_letter(""), String::new());
+    }
+
+    #[test]
+    fn try_to_type_name_reports_whitespace_instead_of_panicking() {
+        assert_eq!(
+            (&"foo bar").try_to_type_name(),
+            Err(CaseError::InvalidWhitespace)
+        );
+    }
+
+    #[test]
+    fn try_to_type_name_converts_unicode() {
+        assert_eq!((&"ärger_typ").try_to_type_name(), Ok("ÄrgerTyp".to_owned()));
+    }
+
     #[test]
     fn to_type_ident_for_ident() {
         let ident = Ident::new("foo_bar", Span::call_site());